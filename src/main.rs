@@ -1,6 +1,9 @@
+use bevy::asset::LoadState;
 use bevy::prelude::*;
+use bevy_common_assets::json::JsonAssetPlugin;
 use bevy_kira_audio::{Audio, AudioControl, AudioPlugin, AudioSource};
-use rand::Rng;
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
 
 // ===================================
 // ===        CONSTANTES GLOBALES  ===
@@ -15,6 +18,8 @@ const OBSTACLE_MIN_X: f32 = -550.0;
 const OBSTACLE_RESET_X: f32 = 550.0;
 const OBSTACLE_SPEED: f32 = 220.0;
 
+const GAME_RNG_SEED: u32 = 0xC0FF_EE42;
+
 // ===================================
 // ===          COMPONENTES         ===
 // ===================================
@@ -24,6 +29,15 @@ struct Player;
 #[derive(Component)]
 struct Velocity(Vec2); // Velocidad Y (Salto y gravedad)
 
+/// Authoritative simulation position, integrated in `FixedUpdate`.
+#[derive(Component, Default)]
+struct SimPosition(Vec3);
+
+/// Simulation position from the previous `FixedUpdate` tick, used to
+/// interpolate the rendered `Transform` between fixed steps.
+#[derive(Component, Default)]
+struct PrevSimPosition(Vec3);
+
 #[derive(Component)]
 struct Floor;
 
@@ -33,15 +47,32 @@ struct Obstacle;
 #[derive(Component)]
 struct Airborne(bool);
 
+/// Per-obstacle speed multiplier from the `ObstaclePattern` it was spawned
+/// or last recycled from, combined with `Difficulty` in `move_obstacles`.
+#[derive(Component)]
+struct SpeedMultiplier(f32);
+
 #[derive(Component)]
 struct ScoreText;
 
+#[derive(Component)]
+struct BestScoreText;
+
 #[derive(Component)]
 struct AnimationTimer(Timer);
 
 #[derive(Component)]
 struct FrameIndex(usize);
 
+#[derive(Component)]
+struct GameOverText;
+
+#[derive(Component)]
+struct Particle {
+    velocity: Vec2,
+    lifetime: Timer,
+}
+
 // ===================================
 // ===           ESTADOS           ===
 // ===================================
@@ -49,6 +80,7 @@ struct FrameIndex(usize);
 enum GameState {
     #[default] Menu,
     Playing,
+    Paused,
     GameOver,
 }
 
@@ -66,6 +98,11 @@ struct Score {
     value: f32,
 }
 
+/// Jump requests sampled in `PreUpdate` and consumed by the next `FixedUpdate`
+/// tick, since `just_pressed` edges don't survive across fixed steps.
+#[derive(Resource, Default)]
+struct PendingJump(bool);
+
 #[derive(Resource)]
 struct PlayerFrames {
     frames: Vec<Handle<Image>>,
@@ -87,6 +124,230 @@ struct ObstacleTextures {
     air: Handle<Image>,
 }
 
+/// Deterministic xorshift RNG, seeded once at startup so obstacle layouts
+/// can be reproduced for replays, daily-challenge seeds, or tests.
+/// Jump and death particle bursts draw from the same generator, so the
+/// layout for a seed also depends on when the player jumps.
+#[derive(Resource)]
+struct GameRng {
+    state: u32,
+}
+
+impl GameRng {
+    fn new(seed: u32) -> Self {
+        // Xorshift collapses to 0 forever if seeded with 0.
+        Self { state: if seed == 0 { 1 } else { seed } }
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+
+    fn gen_bool(&mut self) -> bool {
+        (self.next_u32() & 1) == 0
+    }
+
+    fn gen_range(&mut self, min: u32, max: u32) -> u32 {
+        min + (self.next_u32() % (max - min))
+    }
+}
+
+#[cfg(test)]
+mod game_rng_tests {
+    use super::*;
+
+    #[test]
+    fn known_sequence_for_seed_one() {
+        let mut rng = GameRng::new(1);
+        assert_eq!(rng.next_u32(), 270369);
+        assert_eq!(rng.next_u32(), 67634689);
+    }
+
+    #[test]
+    fn zero_seed_is_clamped_to_one() {
+        let zero_seeded = GameRng::new(0);
+        let one_seeded = GameRng::new(1);
+        assert_eq!(zero_seeded.state, one_seeded.state);
+    }
+
+    #[test]
+    fn gen_bool_matches_next_u32_parity() {
+        let mut rng = GameRng::new(1);
+        let mut parity_rng = GameRng::new(1);
+        for _ in 0..10 {
+            assert_eq!(rng.gen_bool(), (parity_rng.next_u32() & 1) == 0);
+        }
+    }
+
+    #[test]
+    fn gen_range_stays_within_bounds() {
+        let mut rng = GameRng::new(42);
+        for _ in 0..100 {
+            let value = rng.gen_range(5, 10);
+            assert!((5..10).contains(&value));
+        }
+    }
+}
+
+/// Best score ever reached, persisted to disk across sessions.
+#[derive(Resource, Serialize, Deserialize)]
+struct HighScore {
+    best: f32,
+}
+
+fn high_score_path() -> std::path::PathBuf {
+    ProjectDirs::from("", "", "bevy_final")
+        .map(|dirs| dirs.data_dir().join("highscore.json"))
+        .unwrap_or_else(|| std::path::PathBuf::from("highscore.json"))
+}
+
+fn load_high_score() -> HighScore {
+    std::fs::read_to_string(high_score_path())
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or(HighScore { best: 0.0 })
+}
+
+fn save_high_score(high_score: &HighScore) {
+    let path = high_score_path();
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Ok(contents) = serde_json::to_string(high_score) {
+        let _ = std::fs::write(path, contents);
+    }
+}
+
+/// Whether an obstacle pattern spawns airborne, or rolls the dice at recycle time.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[serde(untagged)]
+enum AirborneSpec {
+    Fixed(bool),
+    Random(RandomKeyword),
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+enum RandomKeyword {
+    #[serde(rename = "random")]
+    Random,
+}
+
+impl AirborneSpec {
+    fn resolve(self, rng: &mut GameRng) -> bool {
+        match self {
+            AirborneSpec::Fixed(is_airborne) => is_airborne,
+            AirborneSpec::Random(RandomKeyword::Random) => rng.gen_bool(),
+        }
+    }
+}
+
+fn default_speed_multiplier() -> f32 {
+    1.0
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+struct ObstaclePattern {
+    x_offset: f32,
+    airborne: AirborneSpec,
+    #[serde(default = "default_speed_multiplier")]
+    speed_multiplier: f32,
+}
+
+fn default_floor_offset() -> f32 {
+    10.0
+}
+
+fn default_air_offset() -> f32 {
+    90.0
+}
+
+/// JSON-authored obstacle layout and base tuning, loaded via `bevy_common_assets`
+/// so a level can be tweaked without recompiling.
+#[derive(Asset, TypePath, Serialize, Deserialize, Debug)]
+struct LevelConfig {
+    base_speed: f32,
+    spacing: f32,
+    #[serde(default = "default_floor_offset")]
+    floor_offset: f32,
+    #[serde(default = "default_air_offset")]
+    air_offset: f32,
+    patterns: Vec<ObstaclePattern>,
+}
+
+fn default_level_config() -> LevelConfig {
+    LevelConfig {
+        base_speed: OBSTACLE_SPEED,
+        spacing: OBSTACLE_SPACING,
+        floor_offset: default_floor_offset(),
+        air_offset: default_air_offset(),
+        patterns: (0..5)
+            .map(|_| ObstaclePattern {
+                x_offset: 0.0,
+                airborne: AirborneSpec::Random(RandomKeyword::Random),
+                speed_multiplier: 1.0,
+            })
+            .collect(),
+    }
+}
+
+#[derive(Resource)]
+struct LevelConfigHandle(Handle<LevelConfig>);
+
+#[derive(Resource, Default)]
+struct PatternCursor {
+    next: usize,
+}
+
+impl PatternCursor {
+    /// Returns the index of the next pattern to use, wrapping around the list,
+    /// and advances the cursor for the following call.
+    fn advance(&mut self, pattern_count: usize) -> usize {
+        let index = self.next % pattern_count;
+        self.next = self.next.wrapping_add(1);
+        index
+    }
+}
+
+/// Whether the initial obstacle row has been spawned yet. Stays `false` until
+/// `LevelConfig` has either loaded or failed to load, so the hand-authored
+/// JSON layout (not just the constants fallback) controls the obstacles the
+/// player sees from the very first frame of `Playing`.
+#[derive(Resource, Default, PartialEq, Eq)]
+struct ObstaclesReady(bool);
+
+fn resolve_level_config<'a>(
+    level_configs: &'a Assets<LevelConfig>,
+    handle: &LevelConfigHandle,
+    fallback: &'a LevelConfig,
+) -> &'a LevelConfig {
+    match level_configs.get(&handle.0) {
+        Some(config) if !config.patterns.is_empty() => config,
+        _ => fallback,
+    }
+}
+
+/// Current speed multiplier derived from survival time, read by obstacle
+/// movement (and available to animation/audio systems that want to scale too).
+#[derive(Resource)]
+struct Difficulty {
+    speed_multiplier: f32,
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Self { speed_multiplier: 1.0 }
+    }
+}
+
+fn update_difficulty(score: Res<Score>, mut difficulty: ResMut<Difficulty>) {
+    difficulty.speed_multiplier = 1.0 + (score.value / 200.0).min(3.0);
+}
+
 // ===================================
 // ===      PLUGIN: JUGADOR        ===
 // ===================================
@@ -96,11 +357,16 @@ impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, setup)
            .add_systems(Update, start_game.run_if(in_state(GameState::Menu)))
+           .add_systems(PreUpdate, sample_input.run_if(in_state(GameState::Playing)))
+           .add_systems(FixedUpdate, fixed_move_player.run_if(in_state(GameState::Playing)))
            .add_systems(Update, (
-                move_player,
+                interpolate_player,
                 animate_player_frames,
                 update_score,
-           ).run_if(in_state(GameState::Playing)));
+           ).run_if(in_state(GameState::Playing)))
+           .add_systems(Update, toggle_pause)
+           .add_systems(OnEnter(GameState::GameOver), spawn_game_over_text)
+           .add_systems(Update, restart_game.run_if(in_state(GameState::GameOver)));
     }
 }
 
@@ -117,8 +383,14 @@ fn setup(
     setup_frame_resources(&mut commands, &asset_server);
     setup_floor(&mut commands, &asset_server);
     setup_player(&mut commands, &asset_server);
-    setup_obstacles(&mut commands, &asset_server);
-    setup_score_text(&mut commands);
+
+    let level_config_handle = asset_server.load("levels/level1.level.json");
+    commands.insert_resource(LevelConfigHandle(level_config_handle));
+    commands.insert_resource(PatternCursor::default());
+
+    let high_score = load_high_score();
+    setup_score_text(&mut commands, &high_score);
+    commands.insert_resource(high_score);
 }
 
 fn setup_camera_and_background(commands: &mut Commands, asset_server: &AssetServer) {
@@ -192,24 +464,30 @@ fn setup_player(commands: &mut Commands,asset_server: &AssetServer) {
         Transform::from_translation(PLAYER_START_POS).with_scale(Vec3::splat(0.5)),
         Player,
         Velocity(Vec2::ZERO),
+        SimPosition(PLAYER_START_POS),
+        PrevSimPosition(PLAYER_START_POS),
         AnimationTimer(Timer::from_seconds(0.2, TimerMode::Repeating)),
         FrameIndex(0),
     ));
 }
 
-fn setup_obstacles(commands: &mut Commands, asset_server: &AssetServer) {
+fn setup_obstacles(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    rng: &mut GameRng,
+    config: &LevelConfig,
+) {
     let ground = asset_server.load("sprites/obstacle-ground1.png");
     let air = asset_server.load("sprites/obstacle-air1.png");
-    let mut rng = rand::thread_rng();
 
-    for i in 0..5 {
-        let x = OBSTACLE_START_X + i as f32 * OBSTACLE_SPACING;
-        let is_airborne = rng.gen_bool(0.5);
+    for (i, pattern) in config.patterns.iter().enumerate() {
+        let x = OBSTACLE_START_X + pattern.x_offset + i as f32 * config.spacing;
+        let is_airborne = pattern.airborne.resolve(rng);
 
         let (y, texture) = if is_airborne {
-            (FLOOR_Y + 90.0, air.clone())
+            (FLOOR_Y + config.air_offset, air.clone())
         } else {
-            (FLOOR_Y + 10.0, ground.clone())
+            (FLOOR_Y + config.floor_offset, ground.clone())
         };
 
         commands.spawn((
@@ -218,13 +496,14 @@ fn setup_obstacles(commands: &mut Commands, asset_server: &AssetServer) {
                 .with_scale(Vec3::splat(0.7)),
             Obstacle,
             Airborne(is_airborne),
+            SpeedMultiplier(pattern.speed_multiplier),
             AnimationTimer(Timer::from_seconds(0.3, TimerMode::Repeating)),
             FrameIndex(0),
         ));
     }
 }
 
-fn setup_score_text(commands: &mut Commands) {
+fn setup_score_text(commands: &mut Commands, high_score: &HighScore) {
     commands.spawn((
         Text2d::new("Score: 0"),
         TextFont { font_size: 30.0, ..default() },
@@ -232,43 +511,172 @@ fn setup_score_text(commands: &mut Commands) {
         Transform::from_translation(Vec3::new(-350.0, 200.0, 1.0)),
         ScoreText,
     ));
+
+    commands.spawn((
+        Text2d::new(format!("Best: {}", high_score.best.floor() as i32)),
+        TextFont { font_size: 30.0, ..default() },
+        TextColor(Color::WHITE),
+        Transform::from_translation(Vec3::new(-350.0, 160.0, 1.0)),
+        BestScoreText,
+    ));
 }
 
 fn start_game(
     input: Res<ButtonInput<KeyCode>>,
+    obstacles_ready: Res<ObstaclesReady>,
     mut next: ResMut<NextState<GameState>>,
 ) {
-    if input.any_pressed([KeyCode::ArrowUp, KeyCode::Space, KeyCode::Enter]) {
+    if obstacles_ready.0 && input.any_pressed([KeyCode::ArrowUp, KeyCode::Space, KeyCode::Enter]) {
         next.set(GameState::Playing);
     }
 }
 
+fn toggle_pause(
+    input: Res<ButtonInput<KeyCode>>,
+    state: Res<State<GameState>>,
+    mut next: ResMut<NextState<GameState>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if input.just_pressed(KeyCode::KeyP) {
+        match state.get() {
+            GameState::Playing => {
+                next.set(GameState::Paused);
+                virtual_time.pause();
+            }
+            GameState::Paused => {
+                next.set(GameState::Playing);
+                virtual_time.unpause();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn spawn_game_over_text(mut commands: Commands) {
+    commands.spawn((
+        Text2d::new("Game Over - press R to restart"),
+        TextFont { font_size: 30.0, ..default() },
+        TextColor(Color::WHITE),
+        Transform::from_translation(Vec3::new(-320.0, 0.0, 1.0)),
+        GameOverText,
+    ));
+}
+
+fn restart_game(
+    input: Res<ButtonInput<KeyCode>>,
+    mut next: ResMut<NextState<GameState>>,
+    mut commands: Commands,
+    game_over_text: Query<Entity, With<GameOverText>>,
+    mut score: ResMut<Score>,
+    textures: Res<ObstacleTextures>,
+    mut rng: ResMut<GameRng>,
+    level_configs: Res<Assets<LevelConfig>>,
+    level_config_handle: Res<LevelConfigHandle>,
+    mut cursor: ResMut<PatternCursor>,
+    mut player_query: Query<(&mut Transform, &mut SimPosition, &mut PrevSimPosition, &mut Velocity), With<Player>>,
+    mut obstacle_query: Query<(&mut Transform, &mut Sprite, &mut Airborne, &mut SpeedMultiplier), (With<Obstacle>, Without<Player>)>,
+) {
+    if !input.just_pressed(KeyCode::KeyR) {
+        return;
+    }
+
+    for entity in &game_over_text {
+        commands.entity(entity).despawn();
+    }
+
+    score.value = 0.0;
+
+    if let Ok((mut tf, mut pos, mut prev, mut vel)) = player_query.single_mut() {
+        tf.translation = PLAYER_START_POS;
+        pos.0 = PLAYER_START_POS;
+        prev.0 = PLAYER_START_POS;
+        vel.0 = Vec2::ZERO;
+    }
+
+    let fallback_config = default_level_config();
+    let config = resolve_level_config(&level_configs, &level_config_handle, &fallback_config);
+    *cursor = PatternCursor::default();
+
+    for (i, (mut tf, mut sprite, mut airborne, mut speed_multiplier)) in obstacle_query.iter_mut().enumerate() {
+        let pattern = &config.patterns[cursor.advance(config.patterns.len())];
+        let is_airborne = pattern.airborne.resolve(&mut rng);
+        airborne.0 = is_airborne;
+        speed_multiplier.0 = pattern.speed_multiplier;
+        tf.translation.x = OBSTACLE_START_X + pattern.x_offset + i as f32 * config.spacing;
+        tf.translation.y = if is_airborne {
+            FLOOR_Y + config.air_offset
+        } else {
+            FLOOR_Y + config.floor_offset
+        };
+        sprite.image = if is_airborne { textures.air.clone() } else { textures.ground.clone() };
+    }
+
+    next.set(GameState::Playing);
+}
+
 // ----------------------------------
 // SISTEMAS DEL JUGADOR
 // ----------------------------------
-fn move_player(
+fn sample_input(input: Res<ButtonInput<KeyCode>>, mut pending_jump: ResMut<PendingJump>) {
+    if input.just_pressed(KeyCode::ArrowUp) {
+        pending_jump.0 = true;
+    }
+}
+
+fn fixed_move_player(
+    mut commands: Commands,
     input: Res<ButtonInput<KeyCode>>,
-    time: Res<Time>,
+    fixed_time: Res<Time<Fixed>>,
     audio: Res<Audio>,
     audio_handles: Res<AudioHandles>,
-    mut query: Query<(&mut Transform, &mut Velocity), With<Player>>,
+    mut rng: ResMut<GameRng>,
+    mut pending_jump: ResMut<PendingJump>,
+    mut query: Query<(&mut SimPosition, &mut PrevSimPosition, &mut Velocity), With<Player>>,
 ) {
-    if let Ok((mut tf, mut vel)) = query.single_mut() {
-        let delta = time.delta().as_secs_f32();
+    if let Ok((mut pos, mut prev, mut vel)) = query.single_mut() {
+        prev.0 = pos.0;
+
+        let delta = fixed_time.delta().as_secs_f32();
         let gravity = if input.pressed(KeyCode::ArrowDown) { -2000.0 } else { -500.0 };
         let jump_speed = 350.0;
 
-        let on_floor = tf.translation.y <= FLOOR_Y + PLAYER_HEIGHT / 2.0 + 0.1;
-
-        if input.just_pressed(KeyCode::ArrowUp) && on_floor {
-            vel.0.y = jump_speed;
-            audio.play(audio_handles.jump.clone());
+        let on_floor = pos.0.y <= FLOOR_Y + PLAYER_HEIGHT / 2.0 + 0.1;
+
+        if pending_jump.0 {
+            pending_jump.0 = false;
+
+            if on_floor {
+                vel.0.y = jump_speed;
+                audio.play(audio_handles.jump.clone());
+
+                let feet = pos.0 - Vec3::new(0.0, PLAYER_HEIGHT / 2.0, 0.0);
+                spawn_particle_burst(
+                    &mut commands,
+                    &mut rng,
+                    feet,
+                    6,
+                    40.0,
+                    90.0,
+                    Color::srgb(0.8, 0.7, 0.5),
+                    0.35,
+                );
+            }
         }
 
         vel.0.y += gravity * delta;
 
-        tf.translation.y = (tf.translation.y + vel.0.y * delta)
-            .max(FLOOR_Y + PLAYER_HEIGHT / 2.0);
+        pos.0.y = (pos.0.y + vel.0.y * delta).max(FLOOR_Y + PLAYER_HEIGHT / 2.0);
+    }
+}
+
+fn interpolate_player(
+    fixed_time: Res<Time<Fixed>>,
+    mut query: Query<(&SimPosition, &PrevSimPosition, &mut Transform), With<Player>>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+
+    if let Ok((pos, prev, mut tf)) = query.single_mut() {
+        tf.translation = prev.0.lerp(pos.0, alpha);
     }
 }
 
@@ -289,13 +697,19 @@ fn animate_player_frames(
 fn update_score(
     time: Res<Time>,
     mut score: ResMut<Score>,
-    mut query: Query<&mut Text2d, With<ScoreText>>,
+    high_score: Res<HighScore>,
+    mut score_query: Query<&mut Text2d, With<ScoreText>>,
+    mut best_query: Query<&mut Text2d, (With<BestScoreText>, Without<ScoreText>)>,
 ) {
     score.value += time.delta().as_secs_f32() * 5.0;
 
-    if let Ok(mut text) = query.single_mut() {
+    if let Ok(mut text) = score_query.single_mut() {
         text.0 = format!("Score: {}", score.value.floor() as i32);
     }
+
+    if let Ok(mut text) = best_query.single_mut() {
+        text.0 = format!("Best: {}", high_score.best.floor() as i32);
+    }
 }
 
 // ===================================
@@ -305,12 +719,37 @@ pub struct ObstaclePlugin;
 
 impl Plugin for ObstaclePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (
-            move_obstacles,
-            animate_obstacle_frames,
-            check_collision,
-        ).run_if(in_state(GameState::Playing)));
+        app.add_systems(Update, spawn_obstacles_when_ready.run_if(resource_equals(ObstaclesReady(false))))
+           .add_systems(Update, (
+                update_difficulty,
+                move_obstacles,
+                animate_obstacle_frames,
+                check_collision,
+           ).chain().run_if(in_state(GameState::Playing)));
+    }
+}
+
+/// Spawns the initial obstacle row once `LevelConfig` has either finished
+/// loading or failed, so the JSON layout (not just the constants fallback)
+/// has a real chance to control the obstacles seen from the first frame.
+fn spawn_obstacles_when_ready(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    level_configs: Res<Assets<LevelConfig>>,
+    level_config_handle: Res<LevelConfigHandle>,
+    mut rng: ResMut<GameRng>,
+    mut obstacles_ready: ResMut<ObstaclesReady>,
+) {
+    let load_state = asset_server.load_state(&level_config_handle.0);
+    let settled = matches!(load_state, LoadState::Loaded | LoadState::Failed(_));
+    if !settled {
+        return;
     }
+
+    let fallback_config = default_level_config();
+    let config = resolve_level_config(&level_configs, &level_config_handle, &fallback_config);
+    setup_obstacles(&mut commands, &asset_server, &mut rng, config);
+    obstacles_ready.0 = true;
 }
 
 // ----------------------------------
@@ -319,19 +758,34 @@ impl Plugin for ObstaclePlugin {
 fn move_obstacles(
     time: Res<Time>,
     textures: Res<ObstacleTextures>,
-    mut query: Query<(&mut Transform, &mut Sprite, &mut Airborne), With<Obstacle>>,
+    mut rng: ResMut<GameRng>,
+    level_configs: Res<Assets<LevelConfig>>,
+    level_config_handle: Res<LevelConfigHandle>,
+    mut cursor: ResMut<PatternCursor>,
+    difficulty: Res<Difficulty>,
+    mut query: Query<(&mut Transform, &mut Sprite, &mut Airborne, &mut SpeedMultiplier), With<Obstacle>>,
 ) {
+    let fallback_config = default_level_config();
+    let config = resolve_level_config(&level_configs, &level_config_handle, &fallback_config);
     let delta = time.delta().as_secs_f32();
-    let mut rng = rand::thread_rng();
 
-    for (mut tf, mut sprite, mut airborne_state) in query.iter_mut() {
-        tf.translation.x -= OBSTACLE_SPEED * delta;
+    for (mut tf, mut sprite, mut airborne_state, mut speed_multiplier) in query.iter_mut() {
+        tf.translation.x -= config.base_speed * difficulty.speed_multiplier * speed_multiplier.0 * delta;
 
         if tf.translation.x < OBSTACLE_MIN_X {
-            airborne_state.0 = rng.gen_bool(0.5);
-
-            tf.translation.x = OBSTACLE_RESET_X;
-            tf.translation.y = if airborne_state.0 { FLOOR_Y + 90.0 } else { FLOOR_Y + 10.0 };
+            let pattern = &config.patterns[cursor.advance(config.patterns.len())];
+            airborne_state.0 = pattern.airborne.resolve(&mut rng);
+            speed_multiplier.0 = pattern.speed_multiplier;
+
+            // Pull the respawn point in as speed rises, shortening the gap
+            // between obstacles so they arrive closer together in time as well.
+            let reset_x = OBSTACLE_RESET_X / difficulty.speed_multiplier + pattern.x_offset;
+            tf.translation.x = reset_x;
+            tf.translation.y = if airborne_state.0 {
+                FLOOR_Y + config.air_offset
+            } else {
+                FLOOR_Y + config.floor_offset
+            };
 
             sprite.image = if airborne_state.0 {
                 textures.air.clone()
@@ -363,22 +817,111 @@ fn animate_obstacle_frames(
 }
 
 fn check_collision(
+    mut commands: Commands,
     mut next: ResMut<NextState<GameState>>,
     audio: Res<Audio>,
     handles: Res<AudioHandles>,
+    score: Res<Score>,
+    mut high_score: ResMut<HighScore>,
+    mut rng: ResMut<GameRng>,
     player_query: Query<&Transform, With<Player>>,
     obstacle_query: Query<&Transform, With<Obstacle>>,
+    mut best_query: Query<&mut Text2d, With<BestScoreText>>,
 ) {
     if let Ok(player) = player_query.single() {
         for obstacle in obstacle_query.iter() {
             if player.translation.distance(obstacle.translation) < 50.0 {
                 audio.play(handles.game_over.clone());
+
+                if score.value > high_score.best {
+                    high_score.best = score.value;
+                    save_high_score(&high_score);
+
+                    if let Ok(mut text) = best_query.single_mut() {
+                        text.0 = format!("Best: {}", high_score.best.floor() as i32);
+                    }
+                }
+
+                spawn_particle_burst(
+                    &mut commands,
+                    &mut rng,
+                    player.translation,
+                    16,
+                    120.0,
+                    260.0,
+                    Color::srgb(0.9, 0.2, 0.1),
+                    0.6,
+                );
+
                 next.set(GameState::GameOver);
             }
         }
     }
 }
 
+// ===================================
+// ===     PLUGIN: PARTICULAS      ===
+// ===================================
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update_particles.run_if(in_playing_or_game_over));
+    }
+}
+
+fn in_playing_or_game_over(state: Res<State<GameState>>) -> bool {
+    matches!(state.get(), GameState::Playing | GameState::GameOver)
+}
+
+// ----------------------------------
+// SISTEMAS DE PARTICULAS
+// ----------------------------------
+#[allow(clippy::too_many_arguments)]
+fn spawn_particle_burst(
+    commands: &mut Commands,
+    rng: &mut GameRng,
+    origin: Vec3,
+    count: u32,
+    min_speed: f32,
+    max_speed: f32,
+    color: Color,
+    lifetime_secs: f32,
+) {
+    for _ in 0..count {
+        let angle = (rng.gen_range(0, 360) as f32).to_radians();
+        let speed = min_speed + (rng.gen_range(0, 100) as f32 / 100.0) * (max_speed - min_speed);
+        let velocity = Vec2::new(angle.cos(), angle.sin().abs()) * speed;
+
+        commands.spawn((
+            Sprite::from_color(color, Vec2::splat(6.0)),
+            Transform::from_translation(origin),
+            Particle {
+                velocity,
+                lifetime: Timer::from_seconds(lifetime_secs, TimerMode::Once),
+            },
+        ));
+    }
+}
+
+fn update_particles(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Transform, &mut Sprite, &mut Particle)>,
+) {
+    let delta = time.delta().as_secs_f32();
+
+    for (entity, mut tf, mut sprite, mut particle) in &mut query {
+        particle.lifetime.tick(time.delta());
+        tf.translation += particle.velocity.extend(0.0) * delta;
+        sprite.color.set_alpha(particle.lifetime.fraction_remaining());
+
+        if particle.lifetime.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
 // ===================================
 // ===        MAIN APP             ===
 // ===================================
@@ -386,10 +929,17 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(AudioPlugin)
+        .add_plugins(JsonAssetPlugin::<LevelConfig>::new(&["level.json"]))
         .init_resource::<AudioHandles>()
         .init_resource::<Score>()
+        .init_resource::<Difficulty>()
+        .init_resource::<PendingJump>()
+        .init_resource::<ObstaclesReady>()
+        .insert_resource(GameRng::new(GAME_RNG_SEED))
+        .insert_resource(Time::<Fixed>::from_hz(60.0))
         .init_state::<GameState>()
         .add_plugins(PlayerPlugin)
         .add_plugins(ObstaclePlugin)
+        .add_plugins(ParticlePlugin)
         .run();
 }